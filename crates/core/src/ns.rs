@@ -1,5 +1,11 @@
 use std::collections::HashSet;
 
+/// A namespace of identifiers used to mint unique local names in generated
+/// code.
+///
+/// Naming is deterministic: the set of defined names is only ever queried for
+/// membership, never iterated, so the same sequence of `insert` and `tmp`
+/// calls always yields the same names.
 #[derive(Default)]
 pub struct Ns {
     defined: HashSet<String>,
@@ -15,6 +21,12 @@ impl Ns {
         }
     }
 
+    /// Returns a fresh name based on `name`.
+    ///
+    /// The first request for `name` returns it verbatim if it's free.
+    /// Otherwise a numeric suffix is appended from a counter shared by all
+    /// names in this namespace, so `tmp("ret")` twice yields `ret` and then
+    /// `ret0`, and a subsequent colliding `tmp("variant")` yields `variant1`.
     pub fn tmp(&mut self, name: &str) -> String {
         let mut ret = name.to_string();
         while self.defined.contains(&ret) {
@@ -25,3 +37,41 @@ impl Ns {
         ret
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Ns;
+
+    fn mint() -> Vec<String> {
+        let mut ns = Ns::default();
+        ns.insert("ptr").unwrap();
+        ["ret", "ret", "variant", "variant", "ptr", "option", "ret"]
+            .iter()
+            .map(|name| ns.tmp(name))
+            .collect()
+    }
+
+    #[test]
+    fn tmp_names() {
+        assert_eq!(
+            mint(),
+            ["ret", "ret0", "variant", "variant1", "ptr2", "option", "ret3"]
+        );
+    }
+
+    #[test]
+    fn deterministic() {
+        let expected = mint();
+        for _ in 0..100 {
+            assert_eq!(mint(), expected);
+        }
+    }
+
+    #[test]
+    fn insert_duplicate() {
+        let mut ns = Ns::default();
+        ns.insert("ret_area").unwrap();
+        assert!(ns.insert("ret_area").is_err());
+        assert_eq!(ns.tmp("ret_area"), "ret_area0");
+    }
+}
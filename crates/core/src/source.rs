@@ -147,7 +147,17 @@ impl From<Source> for String {
 /// Calls [`write!`] with the passed arguments and unwraps the result.
 ///
 /// Useful for writing to things with infallible `Write` implementations like
-/// `Source` and `String`.
+/// `Source` and `String`. As with [`write!`], [`std::fmt::Write`] must be in
+/// scope at the call site.
+///
+/// ```
+/// use std::fmt::Write;
+/// use wit_bindgen_core::{uwrite, Source};
+///
+/// let mut src = Source::default();
+/// uwrite!(src, "fn {}() {{", "foo");
+/// assert_eq!(src.as_str(), "fn foo() {");
+/// ```
 ///
 /// [`write!`]: std::write
 #[macro_export]
@@ -160,7 +170,19 @@ macro_rules! uwrite {
 /// Calls [`writeln!`] with the passed arguments and unwraps the result.
 ///
 /// Useful for writing to things with infallible `Write` implementations like
-/// `Source` and `String`.
+/// `Source` and `String`. As with [`writeln!`], [`std::fmt::Write`] must be in
+/// scope at the call site.
+///
+/// ```
+/// use std::fmt::Write;
+/// use wit_bindgen_core::{uwriteln, Source};
+///
+/// let mut src = Source::default();
+/// uwriteln!(src, "fn {}() {{", "foo");
+/// uwriteln!(src, "bar();");
+/// uwriteln!(src, "}}");
+/// assert_eq!(src.as_str(), "fn foo() {\n  bar();\n}\n");
+/// ```
 ///
 /// [`writeln!`]: std::writeln
 #[macro_export]